@@ -54,3 +54,31 @@ fn __doc_test_compile_fail() {
 fn __doc_test_should_panic() {
     // Intentionally empty.
 }
+
+
+/// The following example fails to compile because `Arc<RefCell<i32>>` is not `Send`:
+/// `RefCell` is not `Sync`, so `Arc<RefCell<i32>>` does not meet the bound either, and
+/// therefore cannot be moved into a spawned thread. This is exactly the guarantee
+/// `RefCell`/`Cell` give up in exchange for their cheap, non-atomic, single-threaded
+/// bookkeeping; see `tests/thread-safety.rs` for the `Mutex`/`RwLock`/atomic counterparts that
+/// do support this.
+///
+/// ```compile_fail,E0277
+/// use std::cell::RefCell;
+/// use std::sync::Arc;
+/// use std::thread;
+///
+/// fn attempt_to_share_refcell_across_threads() {
+///     let shared = Arc::new(RefCell::new(0));
+///     let shared_clone = Arc::clone(&shared);
+///
+///     let handle = thread::spawn(move || {
+///         *shared_clone.borrow_mut() += 1;
+///     });
+///
+///     handle.join().unwrap();
+/// }
+/// ```
+fn __doc_test_thread_safety_compile_fail() {
+    // Intentionally empty.
+}
@@ -1,11 +1,17 @@
+// `common` is compiled separately per test binary, and not every binary that declares
+// `mod common;` uses every item here, so dead-code warnings are disabled for the whole
+// module rather than per item.
+#![allow(dead_code)]
+
 /// Generic test utilities.
-/// 
+///
 /// Other way to have this is as a shared crate listed as dev-dependency.
 /// That allows, for example, running examples and unit tests for the test
 /// utilities themselves.
 ///
 
 use std::any::type_name;
+use std::cell::RefCell;
 
 
 /// Returns the type of the given parameter, as a string slice.
@@ -18,3 +24,25 @@ use std::any::type_name;
 pub fn type_of<T>(_: T) -> &'static str {
     type_name::<T>()
 }
+
+
+/// Describes whether a `RefCell` is currently free to borrow, or already borrowed
+/// (shared or mutably).
+#[derive(Debug, PartialEq, Eq)]
+pub enum BorrowState {
+    Free,
+    Borrowed,
+    MutablyBorrowed,
+}
+
+/// Inspects the current borrow state of `cell` via `try_borrow`/`try_borrow_mut`, without
+/// panicking and without holding on to either guard once the check is done.
+pub fn borrow_state<T>(cell: &RefCell<T>) -> BorrowState {
+    match cell.try_borrow_mut() {
+        Ok(_) => BorrowState::Free,
+        Err(_) => match cell.try_borrow() {
+            Ok(_) => BorrowState::Borrowed,
+            Err(_) => BorrowState::MutablyBorrowed,
+        },
+    }
+}
@@ -1,4 +1,6 @@
 
+mod common;
+
 #[cfg(test)]
 mod test_refcell {
 
@@ -33,6 +35,245 @@ mod test_refcell {
         let inner_string = message_cell.borrow();
         assert_eq!(*inner_string, "Hello world!");
     }
+
+    /// `try_borrow` returns `Err(BorrowError)` instead of panicking when called against a
+    /// live mutable borrow.
+    #[test]
+    fn it_fails_to_try_borrow_while_mutably_borrowed() {
+        let message_cell = RefCell::new( String::from("Hello") );
+
+        let inner_string_mut = message_cell.borrow_mut();
+        assert!(message_cell.try_borrow().is_err(), "A conflicting mutable borrow is still alive.");
+
+        drop(inner_string_mut);
+        assert_eq!(*message_cell.try_borrow().unwrap(), "Hello", "The borrow is free again.");
+    }
+
+    /// `try_borrow_mut` returns `Err(BorrowMutError)` instead of panicking when called
+    /// against any live conflicting borrow.
+    #[test]
+    fn it_fails_to_try_borrow_mut_while_borrowed() {
+        let message_cell = RefCell::new( String::from("Hello") );
+
+        let inner_string = message_cell.borrow();
+        assert!(message_cell.try_borrow_mut().is_err(), "A conflicting shared borrow is still alive.");
+
+        drop(inner_string);
+        assert!(message_cell.try_borrow_mut().is_ok(), "The borrow is free again.");
+    }
+
+    /// Retries `action` against `cell` until a mutable borrow is available.
+    fn retry_borrow_mut<T, R>(cell: &RefCell<T>, mut action: impl FnMut(&mut T) -> R) -> R {
+        loop {
+            if let Ok(mut inner) = cell.try_borrow_mut() {
+                return action(&mut inner);
+            }
+        }
+    }
+
+    #[test]
+    fn it_retries_until_the_mutable_borrow_is_available() {
+        let message_cell = RefCell::new( String::from("Hello") );
+
+        {
+            let _conflicting_borrow = message_cell.borrow();
+            // `_conflicting_borrow` is dropped at the end of this block, freeing the cell
+            // before `retry_borrow_mut` is ever called below.
+        }
+
+        let new_len = retry_borrow_mut(&message_cell, |inner| {
+            inner.push_str(" world!");
+            inner.len()
+        });
+
+        assert_eq!(new_len, "Hello world!".len());
+        assert_eq!(*message_cell.borrow(), "Hello world!");
+    }
+}
+
+
+/// Tests interior mutability by means of the `Cell<T>` smart pointer, which moves values
+/// in and out instead of handing out references the way `RefCell` does.
+#[cfg(test)]
+mod test_cell {
+
+    use std::cell::Cell;
+
+    #[test]
+    fn it_gets_and_sets_a_copy_value() {
+        let value_cell = Cell::new(5);
+        assert_eq!(value_cell.get(), 5);
+
+        value_cell.set(6);
+        assert_eq!(value_cell.get(), 6);
+    }
+
+    #[test]
+    fn it_replaces_and_returns_the_old_value() {
+        let value_cell = Cell::new(String::from("Hello"));
+
+        let old_value = value_cell.replace(String::from("World"));
+        assert_eq!(old_value, "Hello");
+        assert_eq!(value_cell.into_inner(), "World");
+    }
+
+    /// `take` leaves `T::default()` in place and hands back the previous value.
+    #[test]
+    fn it_takes_and_leaves_the_default_in_place() {
+        let value_cell = Cell::new(String::from("Hello"));
+
+        let taken_value = value_cell.take();
+        assert_eq!(taken_value, "Hello");
+        assert_eq!(value_cell.into_inner(), String::default());
+    }
+
+    #[test]
+    fn it_swaps_values_between_two_cells() {
+        let cell_a = Cell::new(1);
+        let cell_b = Cell::new(2);
+
+        cell_a.swap(&cell_b);
+
+        assert_eq!(cell_a.get(), 2);
+        assert_eq!(cell_b.get(), 1);
+    }
+
+    #[test]
+    fn it_consumes_itself_to_yield_the_inner_value() {
+        let value_cell = Cell::new(String::from("Hello"));
+        assert_eq!(value_cell.into_inner(), "Hello");
+    }
+
+    /// Unlike `RefCell`, `set` never panics: `Cell` performs no borrow tracking at all.
+    #[test]
+    fn it_never_panics_unlike_refcell() {
+        use std::cell::RefCell;
+
+        let cell = Cell::new(5);
+        let cell_ref = &cell;
+        cell.set(6); // Allowed even though `cell_ref` is alive: nothing is tracked.
+        assert_eq!(cell_ref.get(), 6);
+
+        let ref_cell = RefCell::new(5);
+        let _borrow = ref_cell.borrow();
+        assert!(ref_cell.try_borrow_mut().is_err(), "`RefCell`, by contrast, rejects the conflicting borrow.");
+    }
+}
+
+
+/// Tests the raw, borrow-tracking-free access `Cell`/`RefCell` offer via `get_mut`/`as_ptr`,
+/// contrasted against the dynamic checks `borrow_mut` performs.
+#[cfg(test)]
+mod test_cell_raw_access {
+
+    use super::common::{borrow_state, BorrowState};
+    use std::cell::{Cell, RefCell};
+
+    /// `get_mut` needs a unique `&mut` reference to the `Cell`, so it hands back a plain
+    /// `&mut T` with no run-time bookkeeping at all.
+    #[test]
+    fn it_gets_a_mutable_reference_with_no_runtime_tracking() {
+        let mut value_cell = Cell::new(5);
+
+        let inner_mut = value_cell.get_mut();
+        *inner_mut += 1;
+
+        assert_eq!(value_cell.get(), 6);
+    }
+
+    /// `as_ptr` returns a raw pointer to the wrapped value; reading through it is `unsafe`.
+    #[test]
+    fn it_reads_through_a_raw_pointer() {
+        let value_cell = Cell::new(5);
+
+        let value = unsafe { *value_cell.as_ptr() };
+        assert_eq!(value, 5);
+    }
+
+    /// `RefCell::get_mut` skips the dynamic borrow check `borrow_mut` performs, which our
+    /// `borrow_state` helper can observe before and after.
+    #[test]
+    fn it_contrasts_get_mut_with_dynamically_checked_borrow_mut() {
+        let mut message_cell = RefCell::new(String::from("Hello"));
+        assert_eq!(borrow_state(&message_cell), BorrowState::Free);
+
+        // `get_mut` needs no borrow check: our unique `&mut message_cell` already proves
+        // there is no other live access to contend with.
+        message_cell.get_mut().push_str(" world!");
+        assert_eq!(borrow_state(&message_cell), BorrowState::Free, "`get_mut` never touches the borrow flag.");
+
+        // `borrow_mut`, by contrast, does set the borrow flag for as long as its guard lives.
+        let guard = message_cell.borrow_mut();
+        assert_eq!(borrow_state(&message_cell), BorrowState::MutablyBorrowed);
+        drop(guard);
+
+        assert_eq!(*message_cell.borrow(), "Hello world!");
+    }
+}
+
+
+/// Tests interior mutability by means of the `OnceCell<T>` smart pointer, which lets a
+/// value be set exactly once through a shared reference, and read freely thereafter.
+#[cfg(test)]
+mod test_once_cell {
+
+    use std::cell::OnceCell;
+
+    #[test]
+    fn it_is_empty_before_initialization() {
+        let value_cell: OnceCell<i32> = OnceCell::new();
+        assert_eq!(value_cell.get(), None);
+    }
+
+    #[test]
+    fn it_sets_once_and_rejects_further_attempts() {
+        let value_cell = OnceCell::new();
+
+        assert_eq!(value_cell.set(5), Ok(()));
+        assert_eq!(value_cell.get(), Some(&5));
+
+        assert_eq!(value_cell.set(6), Err(6), "A second `set` is rejected; the original value is kept.");
+        assert_eq!(value_cell.get(), Some(&5));
+    }
+
+    /// `get_or_init` only runs the closure on the first call; later calls reuse its result.
+    #[test]
+    fn it_initializes_lazily_only_once() {
+        let value_cell = OnceCell::new();
+        let mut init_count = 0;
+
+        let first = *value_cell.get_or_init(|| { init_count += 1; 42 });
+        let second = *value_cell.get_or_init(|| { init_count += 1; 99 });
+
+        assert_eq!(first, 42);
+        assert_eq!(second, 42, "The closure only ran on the first access.");
+        assert_eq!(init_count, 1);
+    }
+
+    /// Mirrors `MockMessenger` from `test_interior_mutability`, populating a field lazily
+    /// from an `&self` method instead of repeatedly mutating it.
+    struct CachingGreeter {
+        greeting: OnceCell<String>,
+    }
+
+    impl CachingGreeter {
+        fn new() -> CachingGreeter {
+            CachingGreeter { greeting: OnceCell::new() }
+        }
+
+        fn greeting(&self) -> &str {
+            self.greeting.get_or_init(|| String::from("Hello, world!"))
+        }
+    }
+
+    #[test]
+    fn it_populates_a_struct_field_lazily_from_an_immutable_method() {
+        let greeter = CachingGreeter::new();
+        assert_eq!(greeter.greeting.get(), None);
+
+        assert_eq!(greeter.greeting(), "Hello, world!");
+        assert_eq!(greeter.greeting.get(), Some(&String::from("Hello, world!")));
+    }
 }
 
 
@@ -136,6 +377,48 @@ mod test_interior_mutability {
 }
 
 
+/// Tests narrowing a `RefCell` borrow to a component of the borrowed data, via
+/// `Ref::map` / `RefMut::map`.
+/// [Rust Book section 15.5]
+#[cfg(test)]
+mod test_ref_map {
+
+    use std::cell::{Ref, RefCell, RefMut};
+
+    #[test]
+    fn it_maps_an_immutable_borrow_to_a_single_element() {
+        let list_cell = RefCell::new(vec![String::from("first"), String::from("second")]);
+
+        let first_element = Ref::map(list_cell.borrow(), |list| &list[0]);
+        assert_eq!(*first_element, "first");
+    }
+
+    #[test]
+    fn it_maps_a_mutable_borrow_to_a_single_element() {
+        let list_cell = RefCell::new(vec![String::from("first"), String::from("second")]);
+
+        {
+            let mut first_element = RefMut::map(list_cell.borrow_mut(), |list| &mut list[0]);
+            first_element.push('!');
+        }
+
+        assert_eq!(*list_cell.borrow(), vec!["first!", "second"]);
+    }
+
+    /// The mapped guard keeps the borrow flag set, so a conflicting borrow still fails.
+    #[test]
+    fn it_keeps_the_borrow_flag_held_while_the_mapped_guard_is_alive() {
+        let list_cell = RefCell::new(vec![String::from("first"), String::from("second")]);
+
+        let first_element_mut = RefMut::map(list_cell.borrow_mut(), |list| &mut list[0]);
+        assert!(list_cell.try_borrow_mut().is_err(), "The mapped `RefMut` still holds the borrow.");
+
+        drop(first_element_mut);
+        assert!(list_cell.try_borrow_mut().is_ok(), "The borrow is released once the mapped guard is dropped.");
+    }
+}
+
+
 /// Tests the Multiple Interior Mutability pattern.
 /// [Rust Book section 15.5]
 ///
@@ -0,0 +1,152 @@
+
+/// Re-implements the `LimitTracker`/`Messenger` scenario from `test_interior_mutability`
+/// using thread-safe primitives, so it can be driven concurrently from several threads:
+/// an `AtomicUsize` for the usage counter (the multi-threaded analogue of `Cell`), and a
+/// `Mutex`- or `RwLock`-backed messenger (the multi-threaded analogue of `RefCell`).
+#[cfg(test)]
+mod test_thread_safety {
+
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::{Arc, Mutex, RwLock};
+    use std::thread;
+
+    /// Interface for an object able to `send` messages "somewhere"; same shape as
+    /// `test_interior_mutability::Messenger`, but `Send + Sync` since it is shared
+    /// across threads.
+    pub trait Messenger: Send + Sync {
+        fn send(&self, msg: &str);
+    }
+
+    /// Tracks usage across threads, sending warning messages through a `Messenger` when
+    /// specific limits are reached; the thread-safe counterpart of
+    /// `test_interior_mutability::LimitTracker`.
+    pub struct LimitTracker<T: Messenger> {
+        messenger: T,     // Owned, not borrowed: threads need to share ownership via `Arc`.
+        usage:     AtomicUsize,
+        max_usage: usize,
+    }
+
+    impl<T> LimitTracker<T>
+    where
+        T: Messenger,
+    {
+        /// Constructor
+        pub fn new(messenger: T, max_usage: usize) -> LimitTracker<T> {
+            LimitTracker { messenger, usage: AtomicUsize::new(0), max_usage }
+        }
+
+        /// Adds `amount` to the usage counter and sends a warning the moment a threshold is
+        /// crossed. Takes `&self`, not `&mut self`: `fetch_add` mutates through a shared
+        /// reference, just as `MockMessenger::send` does via `RefCell` in the single-threaded
+        /// version.
+        ///
+        /// `fetch_add` hands back the pre-increment value, so each call can tell whether
+        /// *this* addition is the one that pushed usage past a threshold, rather than
+        /// re-sending a warning on every subsequent call once usage is already past it.
+        pub fn add_usage(&self, amount: usize) {
+            let previous_usage = self.usage.fetch_add(amount, Ordering::SeqCst);
+            let usage = previous_usage + amount;
+
+            let previous_percent = previous_usage as f64 / self.max_usage as f64;
+            let usage_percent    = usage          as f64 / self.max_usage as f64;
+
+            if previous_percent < 1.0 && usage_percent >= 1.0 {
+                self.messenger.send("ERROR: Quota exceeded.");
+            }
+            else if previous_percent < 0.9 && usage_percent >= 0.9 {
+                self.messenger.send("WARNING: Reached 90% of quota.");
+            }
+            else if previous_percent < 0.75 && usage_percent >= 0.75 {
+                self.messenger.send("INFO: Reached 75% of quota.");
+            }
+        }
+    }
+
+    /// A `Messenger` that collects messages behind a `Mutex`, the thread-safe counterpart
+    /// of the `RefCell` used by `MockMessenger`.
+    struct MutexMessenger {
+        sent_messages: Mutex<Vec<String>>,
+    }
+
+    impl MutexMessenger {
+        /// Constructor
+        fn new() -> MutexMessenger {
+            MutexMessenger { sent_messages: Mutex::new(vec![]) }
+        }
+    }
+
+    impl Messenger for MutexMessenger {
+        fn send(&self, message: &str) {
+            self.sent_messages.lock().unwrap().push(String::from(message));
+        }
+    }
+
+    /// A `Messenger` that collects messages behind a `RwLock`: `send` takes the write lock
+    /// (mirroring `Mutex`'s `lock`), while readers can take the shared read lock without
+    /// blocking each other.
+    struct RwLockMessenger {
+        sent_messages: RwLock<Vec<String>>,
+    }
+
+    impl RwLockMessenger {
+        /// Constructor
+        fn new() -> RwLockMessenger {
+            RwLockMessenger { sent_messages: RwLock::new(vec![]) }
+        }
+    }
+
+    impl Messenger for RwLockMessenger {
+        fn send(&self, message: &str) {
+            self.sent_messages.write().unwrap().push(String::from(message));
+        }
+    }
+
+    /// Runs `steps` usage increments of `step` each concurrently (one thread per increment)
+    /// against `tracker`, then waits for all of them to finish.
+    fn add_usage_concurrently<T: Messenger + 'static>(tracker: &Arc<LimitTracker<T>>, step: usize, steps: usize) {
+        let handles: Vec<_> = (0..steps)
+            .map(|_| {
+                let tracker = Arc::clone(tracker);
+                thread::spawn(move || tracker.add_usage(step))
+            })
+            .collect();
+
+        for handle in handles {
+            handle.join().unwrap();
+        }
+    }
+
+    #[test]
+    fn it_sends_threshold_warnings_through_a_mutex_messenger_from_multiple_threads() {
+        let tracker = Arc::new(LimitTracker::new(MutexMessenger::new(), 100));
+
+        // 20 threads each add 5 usage, reaching exactly 75%, 90% and 100% along the way.
+        add_usage_concurrently(&tracker, 5, 20);
+
+        // The three threshold messages are each sent exactly once, by whichever thread's
+        // addition happens to cross that boundary; the threads racing to get there give no
+        // guarantee about which of the three messages lands first, so compare as a set.
+        let mut sent_messages = tracker.messenger.sent_messages.lock().unwrap().clone();
+        sent_messages.sort();
+        assert_eq!(sent_messages, vec![
+            "ERROR: Quota exceeded.",
+            "INFO: Reached 75% of quota.",
+            "WARNING: Reached 90% of quota.",
+        ]);
+    }
+
+    #[test]
+    fn it_sends_threshold_warnings_through_a_rwlock_messenger_from_multiple_threads() {
+        let tracker = Arc::new(LimitTracker::new(RwLockMessenger::new(), 100));
+
+        add_usage_concurrently(&tracker, 5, 20);
+
+        let mut sent_messages = tracker.messenger.sent_messages.read().unwrap().clone();
+        sent_messages.sort();
+        assert_eq!(sent_messages, vec![
+            "ERROR: Quota exceeded.",
+            "INFO: Reached 75% of quota.",
+            "WARNING: Reached 90% of quota.",
+        ]);
+    }
+}